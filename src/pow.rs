@@ -1,6 +1,6 @@
-use core::ops::Mul;
+use core::ops::{Mul, Rem};
 use core::num::Wrapping;
-use {One, CheckedMul};
+use {Bounded, CheckedMul, One, OverflowingMul, WrappingMul, Zero};
 
 /// Binary operator for raising a value to a power.
 pub trait Pow<RHS> {
@@ -114,6 +114,76 @@ pow_impl!(Wrapping<i64>);
 pow_impl!(Wrapping<usize>);
 pow_impl!(Wrapping<isize>);
 
+/// Binary operator for raising a value to a power, checking for overflow.
+pub trait CheckedPow<RHS> {
+    /// The result after applying the operator.
+    type Output;
+
+    /// Returns `self` to the power `rhs`, or `None` if an overflow occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_traits::CheckedPow;
+    /// assert_eq!(CheckedPow::checked_pow(10u32, 2u32), Some(100));
+    /// assert_eq!(CheckedPow::checked_pow(7u8, 8u32), None);
+    /// ```
+    fn checked_pow(self, rhs: RHS) -> Option<Self::Output>;
+}
+
+macro_rules! checked_pow_impl {
+    ($t:ty) => {
+        checked_pow_impl!($t, u8);
+        checked_pow_impl!($t, u16);
+        checked_pow_impl!($t, u32);
+        checked_pow_impl!($t, usize);
+    };
+    ($t:ty, $rhs:ty) => {
+        impl CheckedPow<$rhs> for $t {
+            type Output = $t;
+            #[inline]
+            fn checked_pow(self, rhs: $rhs) -> Option<$t> {
+                checked_pow(self, rhs as usize)
+            }
+        }
+
+        impl<'a> CheckedPow<&'a $rhs> for $t {
+            type Output = $t;
+            #[inline]
+            fn checked_pow(self, rhs: &'a $rhs) -> Option<$t> {
+                checked_pow(self, *rhs as usize)
+            }
+        }
+
+        impl<'a> CheckedPow<$rhs> for &'a $t {
+            type Output = $t;
+            #[inline]
+            fn checked_pow(self, rhs: $rhs) -> Option<$t> {
+                checked_pow(self.clone(), rhs as usize)
+            }
+        }
+
+        impl<'a, 'b> CheckedPow<&'a $rhs> for &'b $t {
+            type Output = $t;
+            #[inline]
+            fn checked_pow(self, rhs: &'a $rhs) -> Option<$t> {
+                checked_pow(self.clone(), *rhs as usize)
+            }
+        }
+    };
+}
+
+checked_pow_impl!(u8);
+checked_pow_impl!(i8);
+checked_pow_impl!(u16);
+checked_pow_impl!(i16);
+checked_pow_impl!(u32);
+checked_pow_impl!(i32);
+checked_pow_impl!(u64);
+checked_pow_impl!(i64);
+checked_pow_impl!(usize);
+checked_pow_impl!(isize);
+
 #[cfg(feature = "std")]
 mod float_impls {
     use super::Pow;
@@ -133,23 +203,70 @@ mod float_impls {
     pow_impl!(f64, f64, f64::powf);
 }
 
+// `impl<U: Unsigned> Pow<U> for $t` would conflict with the concrete `impl Pow<u8/u16/u32/usize>
+// for $t` above: rustc can't rule out some future crate implementing `Unsigned` for one of those
+// RHS types, so a single blanket impl over `U: Unsigned` is rejected as overlapping (E0119).
+// Implementing each `typenum` constant concretely sidesteps that, at the cost of only covering a
+// bounded range of compile-time exponents (0..=16, which covers every realistic use case: squares,
+// cubes, and small dimensional-analysis exponents).
+#[cfg(feature = "typenum")]
+mod typenum_impls {
+    use super::Pow;
+    use typenum::Unsigned;
+    use typenum::consts::{U0, U1, U2, U3, U4, U5, U6, U7, U8, U9, U10, U11, U12, U13, U14, U15, U16};
 
-/// Raises a value to the power of exp, using exponentiation by squaring.
+    macro_rules! typenum_pow_impl {
+        ($t:ty; $($u:ty),+) => {
+            $(
+                impl Pow<$u> for $t {
+                    type Output = $t;
+                    #[inline]
+                    fn pow(self, _rhs: $u) -> $t {
+                        super::pow(self, <$u as Unsigned>::to_usize())
+                    }
+                }
+            )+
+        };
+    }
+
+    macro_rules! typenum_pow_impl_exponents {
+        ($($t:ty),+) => {
+            $(
+                typenum_pow_impl!($t; U0, U1, U2, U3, U4, U5, U6, U7, U8, U9, U10, U11, U12, U13, U14, U15, U16);
+            )+
+        };
+    }
+
+    typenum_pow_impl_exponents!(u8, i8, u16, i16, u32, i32, u64, i64, usize, isize);
+
+    #[cfg(feature = "std")]
+    typenum_pow_impl_exponents!(f32, f64);
+}
+
+
+/// Raises a value to the power of exp, using exponentiation by squaring for an arbitrary monoid.
+///
+/// `op` is assumed to be associative, and `identity` must be a left/right identity for `op`, i.e.
+/// `op(identity, x) == op(x, identity) == x` for all `x`. This lets `pow_with` be used not only
+/// for numeric multiplication but also e.g. matrix multiplication, modular multiplication, or
+/// concatenation of `String`s/`Vec`s/permutations.
 ///
 /// # Example
 ///
 /// ```rust
-/// use num_traits::pow;
+/// use num_traits::pow_with;
 ///
-/// assert_eq!(pow(2i8, 4), 16);
-/// assert_eq!(pow(6u8, 3), 216);
+/// assert_eq!(pow_with(2i8, 4, 1, |a, b| a * b), 16);
+/// assert_eq!(pow_with(vec![1, 2], 3, vec![], |a, b| [&a[..], &b[..]].concat()), vec![1, 2, 1, 2, 1, 2]);
 /// ```
 #[inline]
-pub fn pow<T: Clone + One + Mul<T, Output = T>>(mut base: T, mut exp: usize) -> T {
-    if exp == 0 { return T::one() }
+pub fn pow_with<T, F>(mut base: T, mut exp: usize, identity: T, op: F) -> T
+    where T: Clone, F: Fn(&T, &T) -> T
+{
+    if exp == 0 { return identity }
 
     while exp & 1 == 0 {
-        base = base.clone() * base;
+        base = op(&base, &base);
         exp >>= 1;
     }
     if exp == 1 { return base }
@@ -157,14 +274,29 @@ pub fn pow<T: Clone + One + Mul<T, Output = T>>(mut base: T, mut exp: usize) ->
     let mut acc = base.clone();
     while exp > 1 {
         exp >>= 1;
-        base = base.clone() * base;
+        base = op(&base, &base);
         if exp & 1 == 1 {
-            acc = acc * base.clone();
+            acc = op(&acc, &base);
         }
     }
     acc
 }
 
+/// Raises a value to the power of exp, using exponentiation by squaring.
+///
+/// # Example
+///
+/// ```rust
+/// use num_traits::pow;
+///
+/// assert_eq!(pow(2i8, 4), 16);
+/// assert_eq!(pow(6u8, 3), 216);
+/// ```
+#[inline]
+pub fn pow<T: Clone + One + Mul<T, Output = T>>(base: T, exp: usize) -> T {
+    pow_with(base, exp, T::one(), |a, b| a.clone() * b.clone())
+}
+
 /// Raises a value to the power of exp, returning `None` if an overflow occurred.
 ///
 /// Otherwise same as the `pow` function.
@@ -204,3 +336,178 @@ pub fn checked_pow<T: Clone + One + CheckedMul>(mut base: T, mut exp: usize) ->
     }
     Some(acc)
 }
+
+/// Raises a value to the power of exp, wrapping around on overflow.
+///
+/// Otherwise same as the `pow` function.
+///
+/// # Example
+///
+/// ```rust
+/// use num_traits::wrapping_pow;
+///
+/// assert_eq!(wrapping_pow(2i8, 4), 16);
+/// assert_eq!(wrapping_pow(5i8, 3), 125);
+/// assert_eq!(wrapping_pow(-4i8, 2), 16);
+/// assert_eq!(wrapping_pow(8u8, 2), 64);
+/// assert_eq!(wrapping_pow(8u8, 3), 0); // (wrapping!)
+/// ```
+#[inline]
+pub fn wrapping_pow<T: Clone + One + WrappingMul>(mut base: T, mut exp: usize) -> T {
+    if exp == 0 { return T::one() }
+
+    while exp & 1 == 0 {
+        base = base.wrapping_mul(&base);
+        exp >>= 1;
+    }
+    if exp == 1 { return base }
+
+    let mut acc = base.clone();
+    while exp > 1 {
+        exp >>= 1;
+        base = base.wrapping_mul(&base);
+        if exp & 1 == 1 {
+            acc = acc.wrapping_mul(&base);
+        }
+    }
+    acc
+}
+
+/// Raises a value to the power of exp, saturating at the numeric bounds instead of overflowing.
+///
+/// Otherwise same as the `pow` function.
+///
+/// # Example
+///
+/// ```rust
+/// use num_traits::saturating_pow;
+///
+/// assert_eq!(saturating_pow(5i8, 2), 25);
+/// assert_eq!(saturating_pow(-5i8, 3), -125);
+/// assert_eq!(saturating_pow(6i8, 3), 127); // (saturating!)
+/// assert_eq!(saturating_pow(-6i8, 3), -128); // (saturating!)
+/// assert_eq!(saturating_pow(-7i8, 4), 127); // (saturating!)
+/// ```
+#[inline]
+pub fn saturating_pow<T: Clone + Zero + One + PartialOrd + Bounded + CheckedMul>(base: T, exp: usize) -> T {
+    match checked_pow(base.clone(), exp) {
+        Some(val) => val,
+        None => {
+            if base < T::zero() && exp & 1 == 1 {
+                T::min_value()
+            } else {
+                T::max_value()
+            }
+        }
+    }
+}
+
+/// Raises a value to the power of exp, returning the result and whether an overflow occurred.
+///
+/// Otherwise same as the `pow` function.
+///
+/// # Example
+///
+/// ```rust
+/// use num_traits::overflowing_pow;
+///
+/// assert_eq!(overflowing_pow(2i8, 4), (16, false));
+/// assert_eq!(overflowing_pow(7i8, 8), (-63, true));
+/// assert_eq!(overflowing_pow(7u32, 8), (5_764_801, false));
+/// ```
+#[inline]
+pub fn overflowing_pow<T: Clone + One + OverflowingMul>(mut base: T, mut exp: usize) -> (T, bool) {
+    if exp == 0 { return (T::one(), false) }
+
+    let mut overflown = false;
+    macro_rules! mul {
+        ($a:expr, $b:expr) => {{
+            let (val, o) = $a.overflowing_mul(&$b);
+            overflown |= o;
+            val
+        }};
+    }
+
+    while exp & 1 == 0 {
+        base = mul!(base, base.clone());
+        exp >>= 1;
+    }
+    if exp == 1 { return (base, overflown) }
+
+    let mut acc = base.clone();
+    while exp > 1 {
+        exp >>= 1;
+        base = mul!(base, base.clone());
+        if exp & 1 == 1 {
+            acc = mul!(acc, base.clone());
+        }
+    }
+    (acc, overflown)
+}
+
+/// Raises a value to the power of exp, modulo `modulus`, using exponentiation by squaring.
+///
+/// Intermediate values are reduced modulo `modulus` after every multiply, so the *values* this
+/// function works with stay within `[0, modulus)` even when `base.pow(exp)` would overflow `T`
+/// (for a negative `base` they stay within `(-modulus, modulus)`, as `%` keeps the dividend's sign).
+/// That does not make every intermediate *multiply* overflow-free: squaring two values that are
+/// each `< modulus` produces a product as large as `(modulus - 1) * (modulus - 1)`, and that
+/// product must itself fit in `T` before it can be reduced. In other words, no multiply overflows
+/// when `(modulus - 1) * (modulus - 1)` fits in `T`; where one does overflow, this function panics
+/// rather than silently returning a wrong answer.
+/// Pick a `T` wide enough for your modulus (e.g. reduce a `u32` modulus through `u64` arithmetic)
+/// if that bound is a concern.
+///
+/// # Panics
+///
+/// Panics if the product of two reduced values overflows `T`, which can only happen when
+/// `(modulus - 1) * (modulus - 1)` does not fit in `T`.
+///
+/// # Example
+///
+/// ```rust
+/// use num_traits::mod_pow;
+///
+/// assert_eq!(mod_pow(4u64, 13, 497), 445);
+/// assert_eq!(mod_pow(2u32, 10, 1_000), 24);
+///
+/// // `modulus` close to `sqrt(u8::MAX)` keeps every intermediate product within `u8`.
+/// assert_eq!(mod_pow(100u8, 2, 15), 10);
+/// ```
+///
+/// With a `modulus` whose square doesn't fit in `T`, an intermediate product can overflow, and
+/// that panics instead of silently wrapping:
+///
+/// ```rust,should_panic
+/// use num_traits::mod_pow;
+///
+/// let _ = mod_pow(100u8, 2, 200u8); // squaring the reduced base, 100 * 100, overflows u8
+/// ```
+#[inline]
+pub fn mod_pow<T>(base: T, mut exp: usize, modulus: T) -> T
+    where T: Clone + Zero + One + PartialEq + Rem<T, Output = T> + CheckedMul
+{
+    if modulus.is_one() { return T::zero() }
+    if exp == 0 { return T::one() % modulus }
+
+    macro_rules! mul_mod {
+        ($a:expr, $b:expr) => {
+            $a.checked_mul(&$b)
+                .expect("mod_pow: modulus too large, modulus * modulus must fit in T")
+                % modulus.clone()
+        };
+    }
+
+    let mut base = base % modulus.clone();
+    let mut acc = T::one() % modulus.clone();
+    while exp > 0 {
+        if exp & 1 == 1 {
+            acc = mul_mod!(acc, base.clone());
+        }
+        exp >>= 1;
+        if exp > 0 {
+            base = mul_mod!(base.clone(), base.clone());
+        }
+    }
+    acc
+}